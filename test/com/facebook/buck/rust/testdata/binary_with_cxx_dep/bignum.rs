@@ -0,0 +1,134 @@
+//! Arbitrary-precision addition backend.
+//!
+//! Operands are parsed into sign-magnitude, little-endian base-2^32 limb
+//! vectors and summed limb-by-limb with carry propagation, so sums that would
+//! overflow `i64` (such as `10^40 + 10^40`) are computed exactly, including
+//! mixed-sign operands via magnitude subtraction.
+
+const BASE: u64 = 1 << 32;
+
+/// Add two decimal operands and return their full-precision decimal sum.
+pub fn add(a: &str, b: &str) -> String {
+    let (a_neg, a_limbs) = parse(a);
+    let (b_neg, b_limbs) = parse(b);
+
+    if a_neg == b_neg {
+        // Same sign: magnitudes add, the shared sign carries through.
+        let sum = add_magnitudes(&a_limbs, &b_limbs);
+        format(a_neg, &sum)
+    } else {
+        // Opposite signs: the smaller magnitude subtracts from the larger, and
+        // the result takes the sign of the larger magnitude.
+        match cmp_magnitudes(&a_limbs, &b_limbs) {
+            std::cmp::Ordering::Less => format(b_neg, &sub_magnitudes(&b_limbs, &a_limbs)),
+            _ => format(a_neg, &sub_magnitudes(&a_limbs, &b_limbs)),
+        }
+    }
+}
+
+/// Parse a decimal string into `(negative, limbs)` in base 2^32, little-endian.
+fn parse(s: &str) -> (bool, Vec<u32>) {
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let mut limbs: Vec<u32> = Vec::new();
+    for ch in digits.chars() {
+        let digit = ch.to_digit(10).expect("operand must be a decimal integer") as u64;
+        // limbs = limbs * 10 + digit
+        let mut carry = digit;
+        for limb in limbs.iter_mut() {
+            let acc = *limb as u64 * 10 + carry;
+            *limb = (acc % BASE) as u32;
+            carry = acc / BASE;
+        }
+        while carry != 0 {
+            limbs.push((carry % BASE) as u32);
+            carry /= BASE;
+        }
+    }
+
+    normalize(&mut limbs);
+    (neg, limbs)
+}
+
+/// Add two magnitudes limb-by-limb, treating missing limbs as zero.
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let lhs = *a.get(i).unwrap_or(&0) as u64;
+        let rhs = *b.get(i).unwrap_or(&0) as u64;
+        let acc = lhs + rhs + carry;
+        result.push((acc % BASE) as u32);
+        carry = acc / BASE;
+    }
+    if carry != 0 {
+        result.push(carry as u32);
+    }
+    normalize(&mut result);
+    result
+}
+
+/// Compare two normalized magnitudes.
+fn cmp_magnitudes(a: &[u32], b: &[u32]) -> std::cmp::Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Subtract `b` from `a`, assuming `a >= b` (checked by [`cmp_magnitudes`]).
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for i in 0..a.len() {
+        let lhs = *a.get(i).unwrap_or(&0) as i64;
+        let rhs = *b.get(i).unwrap_or(&0) as i64;
+        let mut diff = lhs - rhs - borrow;
+        if diff < 0 {
+            diff += BASE as i64;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        result.push(diff as u32);
+    }
+    normalize(&mut result);
+    result
+}
+
+/// Strip leading (most-significant) zero limbs.
+fn normalize(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+/// Render sign-magnitude limbs back to a decimal string.
+fn format(neg: bool, limbs: &[u32]) -> String {
+    if limbs.is_empty() {
+        return "0".to_string();
+    }
+
+    // Repeatedly divide the magnitude by 10, collecting remainders.
+    let mut digits = Vec::new();
+    let mut work = limbs.to_vec();
+    while !work.is_empty() {
+        let mut remainder = 0u64;
+        for limb in work.iter_mut().rev() {
+            let acc = remainder * BASE + *limb as u64;
+            *limb = (acc / 10) as u32;
+            remainder = acc % 10;
+        }
+        normalize(&mut work);
+        digits.push(std::char::from_digit(remainder as u32, 10).unwrap());
+    }
+
+    let mut out = String::new();
+    if neg {
+        out.push('-');
+    }
+    out.extend(digits.iter().rev());
+    out
+}
@@ -0,0 +1,79 @@
+//! Addition backend for the `binary_with_cxx_dep` fixture.
+//!
+//! The default backend operates on fixed-width primitives: `add` is generic
+//! over [`Summable`], and the overflow-mode and closure helpers take `i64`.
+//! When the crate is built with the `bignum` feature it is replaced by an
+//! arbitrary-precision backend, so large operands add without overflow.
+
+/// Numeric types that can be summed by [`add`]. Implemented for the integer
+/// and floating-point primitives; custom numeric types (rationals, complex)
+/// can opt in by implementing it.
+#[cfg(not(feature = "bignum"))]
+pub trait Summable {
+    fn add(self, other: Self) -> Self;
+}
+
+#[cfg(not(feature = "bignum"))]
+macro_rules! impl_summable {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Summable for $t {
+                fn add(self, other: Self) -> Self {
+                    self + other
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "bignum"))]
+impl_summable!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+);
+
+#[cfg(not(feature = "bignum"))]
+pub fn add<T: Summable>(a: T, b: T) -> T {
+    a.add(b)
+}
+
+/// Add `a` and `b`, returning `None` on overflow instead of wrapping.
+#[cfg(not(feature = "bignum"))]
+pub fn checked_add(a: i64, b: i64) -> Option<i64> {
+    a.checked_add(b)
+}
+
+/// Add `a` and `b`, clamping to the numeric bounds on overflow.
+#[cfg(not(feature = "bignum"))]
+pub fn saturating_add(a: i64, b: i64) -> i64 {
+    a.saturating_add(b)
+}
+
+/// Add `a` and `b`, wrapping around the numeric bounds on overflow.
+#[cfg(not(feature = "bignum"))]
+pub fn wrapping_add(a: i64, b: i64) -> i64 {
+    a.wrapping_add(b)
+}
+
+/// Capture one operand and return a reusable closure, e.g.
+/// `let add10 = make_adder(10); add10(15)`.
+#[cfg(not(feature = "bignum"))]
+pub fn make_adder(x: i64) -> Box<dyn Fn(i64) -> i64> {
+    Box::new(move |y| add(x, y))
+}
+
+/// Like [`make_adder`], but the returned closure accumulates a running total
+/// across calls, for streaming use.
+#[cfg(not(feature = "bignum"))]
+pub fn make_adder_mut(x: i64) -> Box<dyn FnMut(i64) -> i64> {
+    let mut total = x;
+    Box::new(move |y| {
+        total = add(total, y);
+        total
+    })
+}
+
+#[cfg(feature = "bignum")]
+mod bignum;
+
+#[cfg(feature = "bignum")]
+pub use bignum::add;
@@ -2,11 +2,50 @@
 extern crate adder_static as adder;
 #[cfg(feature = "shared")]
 extern crate adder_shared as adder;
+#[cfg(feature = "bignum")]
+extern crate adder_bignum as adder;
 
+#[cfg(not(feature = "bignum"))]
 fn main() {
     let a = 10;
     let b = 15;
+
+    // Select the overflow behavior via ADDER_MODE (checked/saturating/wrapping);
+    // anything else falls back to the plain, possibly-wrapping `add`.
+    match std::env::var("ADDER_MODE").as_deref() {
+        Ok("checked") => match adder::checked_add(a, b) {
+            Some(sum) => println!("{} + {} = {}", a, b, sum),
+            None => println!("{} + {} = overflow", a, b),
+        },
+        Ok("saturating") => {
+            let sum = adder::saturating_add(a, b);
+            println!("{} + {} = {}", a, b, sum);
+        }
+        Ok("wrapping") => {
+            let sum = adder::wrapping_add(a, b);
+            println!("{} + {} = {}", a, b, sum);
+        }
+        _ => {
+            let sum = adder::add(a, b);
+            println!("{} + {} = {}", a, b, sum);
+        }
+    }
+
+    // Partial application: build a reusable adder that captures the first operand.
+    let add10 = adder::make_adder(a);
+    println!("{} + {} = {}", a, b, add10(b));
+
+    // Streaming variant: the closure accumulates a running total across calls.
+    let mut running = adder::make_adder_mut(a);
+    running(b);
+    println!("running total = {}", running(b));
+}
+
+#[cfg(feature = "bignum")]
+fn main() {
+    let a = "10000000000000000000000000000000000000000";
+    let b = "10000000000000000000000000000000000000000";
     let sum = adder::add(a, b);
 
     println!("{} + {} = {}", a, b, sum);
-}
\ No newline at end of file
+}